@@ -0,0 +1,165 @@
+//! [AsciiInsensitive] implementation, the ASCII-only counterpart to [Insensitive].
+
+use ::core::{fmt::Debug, hash::Hash};
+
+use bytemuck::TransparentWrapper;
+
+use crate::{insensitive_display::InsensitiveDisplay, Insensitive};
+
+/// ASCII-only case insensitive byte slice DST, parallel to [Insensitive].
+///
+/// Where [Insensitive] folds through full Unicode uppercasing, [AsciiInsensitive] only folds
+/// the bytes `A`-`Z`/`a`-`z` and leaves every other byte untouched, including multibyte UTF-8
+/// sequences. Its `Eq`/`Ord`/`Hash` impls compare bytes directly instead of decoding `char`s,
+/// which is both correct (ASCII case folding never changes byte length) and cheaper.
+#[repr(transparent)]
+#[derive(TransparentWrapper)]
+pub struct AsciiInsensitive([u8]);
+
+impl AsciiInsensitive {
+    /// Construct a new [AsciiInsensitive].
+    pub fn new<S: AsRef<[u8]> + ?Sized>(s: &S) -> &Self {
+        Self::wrap_ref(s.as_ref())
+    }
+
+    /// Construct a new [AsciiInsensitive] from a byte slice.
+    pub fn from_bytes(bytes: &[u8]) -> &Self {
+        Self::wrap_ref(bytes)
+    }
+
+    /// Construct a new mutable [AsciiInsensitive] from a byte slice.
+    pub fn from_bytes_mut(bytes: &mut [u8]) -> &mut Self {
+        Self::wrap_mut(bytes)
+    }
+
+    /// Get internal bytes as a slice.
+    pub fn as_bytes(&self) -> &[u8] {
+        Self::peel_ref(self)
+    }
+
+    /// Get internal bytes as a slice.
+    pub fn as_bytes_mut(&mut self) -> &mut [u8] {
+        Self::peel_mut(self)
+    }
+
+    /// Get an object that can be used to print self.
+    pub fn display(&self) -> InsensitiveDisplay<'_> {
+        Insensitive::from_bytes(self.as_bytes()).display()
+    }
+
+    /// Get byte count of self.
+    pub fn len(&self) -> usize {
+        self.as_bytes().len()
+    }
+
+    /// Returns true if empty.
+    pub fn is_empty(&self) -> bool {
+        self.as_bytes().is_empty()
+    }
+}
+
+impl AsRef<[u8]> for AsciiInsensitive {
+    fn as_ref(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
+impl AsRef<AsciiInsensitive> for AsciiInsensitive {
+    fn as_ref(&self) -> &AsciiInsensitive {
+        self
+    }
+}
+
+impl Debug for AsciiInsensitive {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        f.debug_tuple("AsciiInsensitive")
+            .field(&self.display())
+            .finish()
+    }
+}
+
+impl Eq for AsciiInsensitive {}
+impl PartialEq for AsciiInsensitive {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_bytes().eq_ignore_ascii_case(other.as_bytes())
+    }
+}
+impl Ord for AsciiInsensitive {
+    fn cmp(&self, other: &Self) -> ::core::cmp::Ordering {
+        self.as_bytes()
+            .iter()
+            .map(u8::to_ascii_uppercase)
+            .cmp(other.as_bytes().iter().map(u8::to_ascii_uppercase))
+    }
+}
+impl PartialOrd for AsciiInsensitive {
+    fn partial_cmp(&self, other: &Self) -> Option<::core::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+impl Hash for AsciiInsensitive {
+    fn hash<H: ::core::hash::Hasher>(&self, state: &mut H) {
+        for byte in self.as_bytes() {
+            H::write_u8(state, byte.to_ascii_uppercase());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ascii_eq_folds_only_ascii_letters() {
+        assert_eq!(AsciiInsensitive::new("HTTP-Header"), AsciiInsensitive::new("http-header"));
+        assert_eq!(AsciiInsensitive::new(""), AsciiInsensitive::new(""));
+        assert_ne!(AsciiInsensitive::new("abc"), AsciiInsensitive::new("abcd"));
+    }
+
+    #[test]
+    fn ascii_eq_leaves_non_ascii_untouched() {
+        // Unlike `Insensitive`, which folds through full Unicode uppercasing, `å`/`Å` must
+        // NOT compare equal here: only `A`-`Z`/`a`-`z` are folded.
+        assert_ne!(AsciiInsensitive::new("å"), AsciiInsensitive::new("Å"));
+        assert_eq!(crate::Insensitive::new("å"), crate::Insensitive::new("Å"));
+    }
+
+    #[test]
+    fn ascii_ord_matches_ascii_uppercased_bytes() {
+        assert_eq!(
+            AsciiInsensitive::new("abc").cmp(AsciiInsensitive::new("ABD")),
+            ::core::cmp::Ordering::Less
+        );
+        assert_eq!(
+            AsciiInsensitive::new("ABC").cmp(AsciiInsensitive::new("abc")),
+            ::core::cmp::Ordering::Equal
+        );
+    }
+
+    #[test]
+    fn ascii_hash_matches_for_ascii_folded_equal_values() {
+        use ::core::hash::{Hash, Hasher};
+
+        fn hash_of(v: &AsciiInsensitive) -> u64 {
+            struct SimpleHasher(u64);
+            impl Hasher for SimpleHasher {
+                fn finish(&self) -> u64 {
+                    self.0
+                }
+                fn write(&mut self, bytes: &[u8]) {
+                    for &b in bytes {
+                        self.0 = self.0.wrapping_mul(31).wrapping_add(b as u64);
+                    }
+                }
+            }
+            let mut hasher = SimpleHasher(0);
+            v.hash(&mut hasher);
+            hasher.finish()
+        }
+
+        assert_eq!(
+            hash_of(AsciiInsensitive::new("AbC123")),
+            hash_of(AsciiInsensitive::new("aBc123"))
+        );
+    }
+}
@@ -5,6 +5,7 @@ use ::core::{
     hash::Hash,
     iter::{Flatten, FusedIterator},
     marker::PhantomData,
+    option,
     str::{Chars, Utf8Chunk, Utf8Chunks},
 };
 
@@ -88,6 +89,39 @@ impl<'a> CaseMap<'a> for CharsLowercaseMap<'a> {
     }
 }
 
+/// Nameable map performing ASCII-only case folding over [str::chars], leaving every non-ASCII
+/// char untouched so multibyte UTF-8 sequences pass through unchanged.
+#[derive(Debug, Clone)]
+#[repr(transparent)]
+pub struct AsciiCaseMap<'a>(Chars<'a>);
+
+impl Iterator for AsciiCaseMap<'_> {
+    type Item = option::IntoIter<char>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next().map(|c| Some(c.to_ascii_uppercase()).into_iter())
+    }
+}
+
+impl DoubleEndedIterator for AsciiCaseMap<'_> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.0
+            .next_back()
+            .map(|c| Some(c.to_ascii_uppercase()).into_iter())
+    }
+}
+
+impl FusedIterator for AsciiCaseMap<'_> {}
+
+impl Sealed for AsciiCaseMap<'_> {}
+impl<'a> CaseMap<'a> for AsciiCaseMap<'a> {
+    type Iter = option::IntoIter<char>;
+
+    fn from_chars(chars: Chars<'a>) -> Self {
+        Self(chars)
+    }
+}
+
 /// A [Utf8Chunk] like struct where the valid part is an uppercase iterator.
 #[repr(transparent)]
 #[derive(Debug, Clone)]
@@ -175,3 +209,40 @@ where
 }
 
 impl<'a, M> FusedIterator for CasedChunks<'a, M> where M: CaseMap<'a> {}
+
+#[cfg(test)]
+mod tests {
+    extern crate alloc;
+
+    use super::*;
+    use crate::Insensitive;
+
+    #[test]
+    fn ascii_case_map_folds_ascii_letters_only() {
+        let folded: alloc::string::String = AsciiCaseMap::from_chars("aA1å".chars())
+            .flatten()
+            .collect();
+        assert_eq!(folded, "AA1å");
+    }
+
+    #[test]
+    fn ascii_chunks_diverge_from_upper_chunks_on_non_ascii() {
+        // `upper_chunks` performs full Unicode folding, so "å"/"Å" compare equal there, but
+        // `ascii_chunks` must leave non-ASCII letters untouched and therefore see them as
+        // different.
+        let lower = Insensitive::new("å");
+        let upper = Insensitive::new("Å");
+
+        assert!(lower.upper_chunks().eq(upper.upper_chunks()));
+        assert!(!lower.ascii_chunks().eq(upper.ascii_chunks()));
+    }
+
+    #[test]
+    fn ascii_chunks_agree_with_upper_chunks_on_ascii() {
+        let lower = Insensitive::new("abc123");
+        let upper = Insensitive::new("ABC123");
+
+        assert!(lower.ascii_chunks().eq(upper.ascii_chunks()));
+        assert!(lower.upper_chunks().eq(upper.upper_chunks()));
+    }
+}
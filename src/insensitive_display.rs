@@ -10,17 +10,91 @@ use crate::Insensitive;
 pub struct InsensitiveDisplay<'f>(pub &'f Insensitive);
 impl Display for InsensitiveDisplay<'_> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        for chunk in self.0.as_bytes().utf8_chunks() {
+        for c in self.0.escape() {
+            write!(f, "{c}")?;
+        }
+        Ok(())
+    }
+}
+impl Debug for InsensitiveDisplay<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        <Self as Display>::fmt(self, f)
+    }
+}
+
+/// Lossy display implementor for [Insensitive], rendering invalid UTF-8 as
+/// `\u{FFFD}` (the standard `OsStr`-style lossy rendering) instead of the `\x'..'` escapes
+/// used by [InsensitiveDisplay].
+#[repr(transparent)]
+#[derive(Clone, Copy)]
+pub struct InsensitiveLossy<'f>(pub &'f Insensitive);
+impl Display for InsensitiveLossy<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let bytes = self.0.as_bytes();
+
+        // Short-circuit so width/precision formatter flags pass through as they would for a
+        // plain `&str`.
+        if let Ok(s) = ::core::str::from_utf8(bytes) {
+            return f.pad(s);
+        }
+
+        for chunk in bytes.utf8_chunks() {
             write!(f, "{}", chunk.valid())?;
-            for c in chunk.invalid() {
-                write!(f, "\\x'{:x}'", c)?;
+            if !chunk.invalid().is_empty() {
+                write!(f, "\u{FFFD}")?;
             }
         }
         Ok(())
     }
 }
-impl Debug for InsensitiveDisplay<'_> {
+impl Debug for InsensitiveLossy<'_> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         <Self as Display>::fmt(self, f)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    extern crate alloc;
+
+    use ::core::fmt::Write;
+
+    use alloc::string::String;
+
+    use super::*;
+
+    fn render(f: impl Display) -> String {
+        let mut out = String::new();
+        write!(out, "{f}").unwrap();
+        out
+    }
+
+    #[test]
+    fn lossy_valid_input_uses_pad_short_circuit() {
+        assert_eq!(render(Insensitive::new("hello").display_lossy()), "hello");
+    }
+
+    #[test]
+    fn lossy_valid_input_respects_width_and_precision() {
+        let s = Insensitive::new("hello");
+        let mut out = String::new();
+        write!(out, "{:>8.3}", s.display_lossy()).unwrap();
+        assert_eq!(out, "     hel");
+    }
+
+    #[test]
+    fn lossy_renders_invalid_run_as_replacement_char() {
+        let bytes: [u8; 5] = [b'a', 0xff, 0xfe, b'b', b'c'];
+        let s = Insensitive::from_bytes(&bytes);
+        // `0xff` and `0xfe` can never start a sequence, so `utf8_chunks` reports each as its
+        // own invalid chunk and each gets a replacement char, as with `from_utf8_lossy`.
+        assert_eq!(render(s.display_lossy()), "a\u{FFFD}\u{FFFD}bc");
+    }
+
+    #[test]
+    fn lossy_renders_trailing_invalid_run_at_eof() {
+        let bytes: [u8; 3] = [b'a', b'b', 0xff];
+        let s = Insensitive::from_bytes(&bytes);
+        assert_eq!(render(s.display_lossy()), "ab\u{FFFD}");
+    }
+}
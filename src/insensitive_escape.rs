@@ -0,0 +1,326 @@
+//! [InsensitiveEscape] implementation, an allocation-free escaping iterator.
+
+use ::core::iter::FusedIterator;
+
+/// One decoded unit of a byte slice: a valid scalar value paired with the number of bytes it
+/// spans, or a single invalid byte (which always spans one byte).
+enum Unit {
+    Char(char, usize),
+    Invalid(u8),
+}
+
+/// Expected length of the UTF-8 sequence started by a leading byte, or `None` if `b` cannot
+/// start a sequence (e.g. it is itself a continuation byte).
+fn utf8_len_from_leading_byte(b: u8) -> Option<usize> {
+    if b & 0x80 == 0 {
+        Some(1)
+    } else if b & 0xE0 == 0xC0 {
+        Some(2)
+    } else if b & 0xF0 == 0xE0 {
+        Some(3)
+    } else if b & 0xF8 == 0xF0 {
+        Some(4)
+    } else {
+        None
+    }
+}
+
+/// Decode the first unit of a non-empty byte slice.
+fn decode_first_unit(slice: &[u8]) -> Unit {
+    if let Some(n) = utf8_len_from_leading_byte(slice[0]) {
+        if n <= slice.len() {
+            if let Ok(s) = ::core::str::from_utf8(&slice[..n]) {
+                if s.len() == n {
+                    if let Some(c) = s.chars().next() {
+                        return Unit::Char(c, n);
+                    }
+                }
+            }
+        }
+    }
+    Unit::Invalid(slice[0])
+}
+
+/// Decode the last unit of a non-empty byte slice.
+fn decode_last_unit(slice: &[u8]) -> Unit {
+    let len = slice.len();
+    let max_back = len.min(4);
+    let mut start = len - 1;
+    for _ in 0..max_back - 1 {
+        if slice[start] & 0xC0 != 0x80 {
+            break;
+        }
+        start -= 1;
+    }
+
+    if let Ok(s) = ::core::str::from_utf8(&slice[start..]) {
+        if s.len() == len - start {
+            if let Some(c) = s.chars().next() {
+                return Unit::Char(c, len - start);
+            }
+        }
+    }
+    Unit::Invalid(slice[len - 1])
+}
+
+/// Iterator over the chars of a single `\x'..'` escape sequence for one invalid byte.
+#[derive(Debug, Clone)]
+struct EscapeChars {
+    buf: [u8; 6],
+    front: u8,
+    back: u8,
+}
+
+impl EscapeChars {
+    fn new(byte: u8) -> Self {
+        const HEX: &[u8; 16] = b"0123456789abcdef";
+
+        let mut buf = [0u8; 6];
+        buf[0] = b'\\';
+        buf[1] = b'x';
+        buf[2] = b'\'';
+
+        let mut len = 3u8;
+        let hi = byte >> 4;
+        if hi != 0 {
+            buf[len as usize] = HEX[hi as usize];
+            len += 1;
+        }
+        buf[len as usize] = HEX[(byte & 0xf) as usize];
+        len += 1;
+        buf[len as usize] = b'\'';
+        len += 1;
+
+        Self {
+            buf,
+            front: 0,
+            back: len,
+        }
+    }
+}
+
+impl Iterator for EscapeChars {
+    type Item = char;
+
+    fn next(&mut self) -> Option<char> {
+        if self.front >= self.back {
+            return None;
+        }
+        let c = self.buf[self.front as usize] as char;
+        self.front += 1;
+        Some(c)
+    }
+}
+
+impl DoubleEndedIterator for EscapeChars {
+    fn next_back(&mut self) -> Option<char> {
+        if self.front >= self.back {
+            return None;
+        }
+        self.back -= 1;
+        Some(self.buf[self.back as usize] as char)
+    }
+}
+
+impl FusedIterator for EscapeChars {}
+
+/// Allocation-free iterator over the escaped textual form of an [Insensitive][crate::Insensitive],
+/// following the stabilized [`ascii::escape_default`][core::ascii::escape_default] design:
+/// valid UTF-8 is yielded char by char, invalid bytes are expanded into the `\x'..'` escape
+/// sequence also used by [InsensitiveDisplay][crate::InsensitiveDisplay].
+pub struct InsensitiveEscape<'a> {
+    bytes: &'a [u8],
+    front: usize,
+    back: usize,
+    front_escape: Option<EscapeChars>,
+    back_escape: Option<EscapeChars>,
+}
+
+impl<'a> InsensitiveEscape<'a> {
+    /// Create a new instance from a [u8] slice.
+    pub(crate) fn new(bytes: &'a [u8]) -> Self {
+        Self {
+            bytes,
+            front: 0,
+            back: bytes.len(),
+            front_escape: None,
+            back_escape: None,
+        }
+    }
+}
+
+impl Iterator for InsensitiveEscape<'_> {
+    type Item = char;
+
+    fn next(&mut self) -> Option<char> {
+        if let Some(c) = self.front_escape.as_mut().and_then(Iterator::next) {
+            return Some(c);
+        }
+        self.front_escape = None;
+
+        if self.front >= self.back {
+            // No raw bytes left to decode, but `back_escape` may still hold characters
+            // buffered by `next_back` (e.g. from a `\x'..'` sequence split across both ends).
+            return self.back_escape.as_mut().and_then(Iterator::next);
+        }
+
+        match decode_first_unit(&self.bytes[self.front..self.back]) {
+            Unit::Char(c, len) => {
+                self.front += len;
+                Some(c)
+            }
+            Unit::Invalid(b) => {
+                self.front += 1;
+                let mut escape = EscapeChars::new(b);
+                let c = escape.next().expect("escape sequence is never empty");
+                self.front_escape = Some(escape);
+                Some(c)
+            }
+        }
+    }
+}
+
+impl DoubleEndedIterator for InsensitiveEscape<'_> {
+    fn next_back(&mut self) -> Option<char> {
+        if let Some(c) = self
+            .back_escape
+            .as_mut()
+            .and_then(DoubleEndedIterator::next_back)
+        {
+            return Some(c);
+        }
+        self.back_escape = None;
+
+        if self.front >= self.back {
+            // No raw bytes left to decode, but `front_escape` may still hold characters
+            // buffered by `next` (e.g. from a `\x'..'` sequence split across both ends).
+            return self
+                .front_escape
+                .as_mut()
+                .and_then(DoubleEndedIterator::next_back);
+        }
+
+        match decode_last_unit(&self.bytes[self.front..self.back]) {
+            Unit::Char(c, len) => {
+                self.back -= len;
+                Some(c)
+            }
+            Unit::Invalid(b) => {
+                self.back -= 1;
+                let mut escape = EscapeChars::new(b);
+                let c = escape.next_back().expect("escape sequence is never empty");
+                self.back_escape = Some(escape);
+                Some(c)
+            }
+        }
+    }
+}
+
+impl FusedIterator for InsensitiveEscape<'_> {}
+
+#[cfg(test)]
+mod tests {
+    extern crate alloc;
+
+    use alloc::string::String;
+
+    use super::*;
+
+    fn escape_string(bytes: &[u8]) -> String {
+        InsensitiveEscape::new(bytes).collect()
+    }
+
+    fn escape_string_rev(bytes: &[u8]) -> String {
+        InsensitiveEscape::new(bytes).rev().collect()
+    }
+
+    #[test]
+    fn escape_passes_through_valid_utf8() {
+        assert_eq!(escape_string(b"abc"), "abc");
+        assert_eq!(escape_string("åäö".as_bytes()), "åäö");
+        assert_eq!(escape_string(b""), "");
+    }
+
+    #[test]
+    fn escape_expands_invalid_bytes() {
+        assert_eq!(escape_string(&[0xfe]), "\\x'fe'");
+        // A lone continuation byte is invalid on its own, regardless of value.
+        assert_eq!(escape_string(&[0x80]), "\\x'80'");
+
+        let mut mixed = alloc::vec::Vec::from(b"a".as_slice());
+        mixed.push(0xfe);
+        mixed.extend_from_slice(b"b");
+        assert_eq!(escape_string(&mixed), "a\\x'fe'b");
+    }
+
+    #[test]
+    fn escape_chars_omits_leading_hex_zero() {
+        // Bytes below 0x80 are always valid UTF-8 on their own and never reach `EscapeChars`
+        // through decoding, but the formatting itself must still match `{:x}` (no zero-pad).
+        let s: String = EscapeChars::new(0x05).collect();
+        assert_eq!(s, "\\x'5'");
+        let s: String = EscapeChars::new(0xfe).collect();
+        assert_eq!(s, "\\x'fe'");
+    }
+
+    #[test]
+    fn escape_reverse_matches_forward_reversed() {
+        let mut mixed = alloc::vec::Vec::from("åäö".as_bytes());
+        mixed.push(0xfe);
+        mixed.extend_from_slice(b"xyz");
+
+        let forward = escape_string(&mixed);
+        let backward = escape_string_rev(&mixed);
+        assert_eq!(backward, forward.chars().rev().collect::<String>());
+    }
+
+    #[test]
+    fn mixed_direction_consumption_does_not_lose_chars() {
+        // Regression test: `next_back` used to only drain `back_escape`, silently dropping
+        // whatever remained buffered in `front_escape` once the byte region was exhausted.
+        let mut escape = InsensitiveEscape::new(&[0xfe]);
+        let first = escape.next();
+        assert_eq!(first, Some('\\'));
+
+        let mut rest = alloc::vec::Vec::new();
+        while let Some(c) = escape.next_back() {
+            rest.push(c);
+        }
+        rest.reverse();
+        let rest: String = rest.into_iter().collect();
+        assert_eq!(rest, "x'fe'");
+    }
+
+    #[test]
+    // `while let` would only cover the first `match` below and silently stop driving
+    // `next_back`, which is the exact interleaving this test exists to exercise.
+    #[allow(clippy::while_let_loop)]
+    fn mixed_direction_consumption_across_multiple_units() {
+        let mut mixed = alloc::vec::Vec::from(b"a".as_slice());
+        mixed.push(0xfe);
+        mixed.extend_from_slice(b"b");
+        mixed.push(0xff);
+        mixed.extend_from_slice(b"c");
+
+        let expected = escape_string(&mixed);
+
+        let mut escape = InsensitiveEscape::new(&mixed);
+        let mut front = alloc::string::String::new();
+        let mut back = alloc::string::String::new();
+        loop {
+            match escape.next() {
+                Some(c) => front.push(c),
+                None => break,
+            }
+            match escape.next_back() {
+                Some(c) => back.push(c),
+                None => break,
+            }
+        }
+
+        let mut collected = front;
+        let back_rev: String = back.chars().rev().collect();
+        collected.push_str(&back_rev);
+        assert_eq!(collected, expected);
+    }
+}
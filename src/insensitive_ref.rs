@@ -5,8 +5,9 @@ use ::core::{fmt::Debug, hash::Hash};
 use bytemuck::TransparentWrapper;
 
 use crate::{
-    insensitive::{CaseMap, CasedChunks, CharsLowercaseMap, CharsUppercaseMap},
-    insensitive_display::InsensitiveDisplay,
+    insensitive::{AsciiCaseMap, CaseMap, CasedChunks, CharsLowercaseMap, CharsUppercaseMap},
+    insensitive_display::{InsensitiveDisplay, InsensitiveLossy},
+    insensitive_escape::InsensitiveEscape,
 };
 
 #[cfg(feature = "alloc")]
@@ -48,6 +49,64 @@ impl Insensitive {
         InsensitiveDisplay(self)
     }
 
+    /// Get an object that can be used to print self, rendering invalid UTF-8 as
+    /// `\u{FFFD}` instead of the `\x'..'` escapes used by [Self::display].
+    pub const fn display_lossy(&self) -> InsensitiveLossy<'_> {
+        InsensitiveLossy(self)
+    }
+
+    /// Iterate over the escaped textual form of self without allocating: valid UTF-8 is
+    /// yielded char by char, invalid bytes are expanded to the `\x'..'` escape sequence used
+    /// by [Self::display].
+    pub fn escape(&self) -> InsensitiveEscape<'_> {
+        InsensitiveEscape::new(self.as_bytes())
+    }
+
+    /// Split self on occurrences of `delim`, yielding each delimiter-separated field as an
+    /// [Insensitive].
+    pub fn split<'a>(&'a self, delim: u8) -> impl Iterator<Item = &'a Insensitive> + 'a {
+        self.as_bytes()
+            .split(move |&b| b == delim)
+            .map(Insensitive::from_bytes)
+    }
+
+    /// Split self on occurrences of `delim` starting from the end, yielding each
+    /// delimiter-separated field as an [Insensitive].
+    pub fn rsplit<'a>(&'a self, delim: u8) -> impl Iterator<Item = &'a Insensitive> + 'a {
+        self.as_bytes()
+            .rsplit(move |&b| b == delim)
+            .map(Insensitive::from_bytes)
+    }
+
+    /// Get the sub-slice containing the last `n` `delim`-separated fields of self, scanning
+    /// backward and counting delimiter occurrences.
+    ///
+    /// Returns the whole buffer if `n` meets or exceeds the number of fields self contains,
+    /// and an empty slice if `n == 0`.
+    pub fn tail(&self, n: usize, delim: u8) -> &Insensitive {
+        let bytes = self.as_bytes();
+
+        if n == 0 {
+            return Insensitive::from_bytes(&bytes[bytes.len()..]);
+        }
+
+        let mut found = 0usize;
+        for (i, _) in bytes.iter().enumerate().rev().filter(|&(_, &b)| b == delim) {
+            found += 1;
+            if found == n {
+                return Insensitive::from_bytes(&bytes[i + 1..]);
+            }
+        }
+
+        Insensitive::from_bytes(bytes)
+    }
+
+    /// Iterate over self as a flat sequence of decoded scalar values, surfacing each byte of
+    /// an invalid UTF-8 run individually rather than grouping it into a chunk.
+    pub fn codepoints<'a>(&'a self) -> impl Iterator<Item = Result<char, u8>> + 'a {
+        codepoints_with_offsets(self.as_bytes()).map(|(unit, _)| unit)
+    }
+
     /// Get byte count of self. Two equal insensitives may have different lengths.
     pub fn len(&self) -> usize {
         self.as_bytes().len()
@@ -73,6 +132,73 @@ impl Insensitive {
         Self::cased_chunks::<'a, CharsLowercaseMap<'a>>(self)
     }
 
+    /// Iterate over self as ASCII-only cased [CasedChunks].
+    pub fn ascii_chunks<'a>(&'a self) -> CasedChunks<'a, AsciiCaseMap<'a>> {
+        Self::cased_chunks::<'a, AsciiCaseMap<'a>>(self)
+    }
+
+    /// Convert the ASCII letters in self to uppercase in place, leaving every other byte
+    /// (including multibyte UTF-8 sequences) untouched. Unlike [Self::encode_upper] this never
+    /// allocates, which is safe since ASCII case changes never alter byte length.
+    pub fn make_ascii_uppercase(&mut self) {
+        self.as_bytes_mut().make_ascii_uppercase()
+    }
+
+    /// Convert the ASCII letters in self to lowercase in place, leaving every other byte
+    /// (including multibyte UTF-8 sequences) untouched. Unlike [Self::encode_lower] this never
+    /// allocates, which is safe since ASCII case changes never alter byte length.
+    pub fn make_ascii_lowercase(&mut self) {
+        self.as_bytes_mut().make_ascii_lowercase()
+    }
+
+    /// Check for ASCII-only case insensitive equality, without allocating and without the full
+    /// Unicode folding performed by [PartialEq].
+    pub fn eq_ignore_ascii_case(&self, other: &Self) -> bool {
+        self.as_bytes().eq_ignore_ascii_case(other.as_bytes())
+    }
+
+    /// Returns `true` if `self` starts with `needle` under the same case folding used by
+    /// [PartialEq].
+    pub fn starts_with(&self, needle: &Self) -> bool {
+        folded_starts_with(self.as_bytes(), needle.as_bytes())
+    }
+
+    /// Returns `true` if `self` ends with `needle` under the same case folding used by
+    /// [PartialEq].
+    pub fn ends_with(&self, needle: &Self) -> bool {
+        if needle.is_empty() {
+            return true;
+        }
+
+        let bytes = self.as_bytes();
+        folded_units(bytes)
+            .map(|(_, start)| start)
+            .any(|start| folded_eq(&bytes[start..], needle.as_bytes()))
+    }
+
+    /// Find the byte offset of the first case-insensitive match of `needle` in `self`.
+    ///
+    /// Matching walks the case-folded `char` streams of both sides in lockstep rather than
+    /// doing a raw byte search, so a match is still found when folding changes a matched
+    /// region's byte length (e.g. `ß` matching `SS`). The returned offset always indexes into
+    /// the original bytes of `self`.
+    pub fn find(&self, needle: &Self) -> Option<usize> {
+        if needle.is_empty() {
+            return Some(0);
+        }
+
+        let bytes = self.as_bytes();
+        folded_units(bytes)
+            .map(|(_, start)| start)
+            .find(|&start| folded_starts_with(&bytes[start..], needle.as_bytes()))
+    }
+
+    /// Returns `true` if `needle` occurs anywhere in `self` under the same case folding used
+    /// by [PartialEq].
+    pub fn contains(&self, needle: &Self) -> bool {
+        self.find(needle).is_some()
+    }
+
     #[cfg(feature = "alloc")]
     /// Encode self as case mapped.
     pub fn encode<'a, M: CaseMap<'a>>(&'a self, buf: &mut alloc::vec::Vec<u8>) {
@@ -111,6 +237,95 @@ impl Insensitive {
     }
 }
 
+/// Unit of a case-folded byte stream: either a folded `char` decoded from valid UTF-8, or a
+/// verbatim byte from an invalid UTF-8 run (invalid bytes are never case-folded).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum FoldedUnit {
+    Char(char),
+    Byte(u8),
+}
+
+/// Decoded scalar values of a byte slice paired with the byte offset each was produced from.
+/// The same building block backs both the public [Insensitive::codepoints] and the case-folded
+/// matching below, rather than each re-deriving its own `utf8_chunks` flattening.
+fn codepoints_with_offsets(bytes: &[u8]) -> impl Iterator<Item = (Result<char, u8>, usize)> + '_ {
+    let mut pos = 0usize;
+    bytes.utf8_chunks().flat_map(move |chunk| {
+        let valid = chunk.valid();
+        let invalid = chunk.invalid();
+        let valid_start = pos;
+        let invalid_start = valid_start + valid.len();
+        pos = invalid_start + invalid.len();
+
+        let valid_units = valid
+            .char_indices()
+            .map(move |(idx, c)| (Ok(c), valid_start + idx));
+        let invalid_units = invalid
+            .iter()
+            .enumerate()
+            .map(move |(idx, &b)| (Err(b), invalid_start + idx));
+
+        valid_units.chain(invalid_units)
+    })
+}
+
+/// Case-fold a single decoded scalar value (or pass an invalid byte through verbatim), yielding
+/// one or more [FoldedUnit]s for it.
+enum FoldChars {
+    Char(::core::char::ToUppercase),
+    Byte(::core::iter::Once<u8>),
+}
+
+impl FoldChars {
+    fn new(unit: Result<char, u8>) -> Self {
+        match unit {
+            Ok(c) => FoldChars::Char(c.to_uppercase()),
+            Err(b) => FoldChars::Byte(::core::iter::once(b)),
+        }
+    }
+}
+
+impl Iterator for FoldChars {
+    type Item = FoldedUnit;
+
+    fn next(&mut self) -> Option<FoldedUnit> {
+        match self {
+            FoldChars::Char(chars) => chars.next().map(FoldedUnit::Char),
+            FoldChars::Byte(byte) => byte.next().map(FoldedUnit::Byte),
+        }
+    }
+}
+
+/// Iterate over the upper-case-folded units of a byte slice (the same folding rule used by
+/// [Insensitive]'s `Eq`/`Ord`/`Hash`), each paired with the byte offset of the source char or
+/// invalid byte it was produced from.
+fn folded_units(bytes: &[u8]) -> impl Iterator<Item = (FoldedUnit, usize)> + '_ {
+    codepoints_with_offsets(bytes)
+        .flat_map(|(unit, offset)| FoldChars::new(unit).map(move |folded| (folded, offset)))
+}
+
+/// Compare two byte slices for full case-folded equality.
+fn folded_eq(a: &[u8], b: &[u8]) -> bool {
+    folded_units(a)
+        .map(|(unit, _)| unit)
+        .eq(folded_units(b).map(|(unit, _)| unit))
+}
+
+/// Check whether `haystack`'s case-folded stream starts with `needle`'s.
+fn folded_starts_with(haystack: &[u8], needle: &[u8]) -> bool {
+    let mut n = folded_units(needle).map(|(unit, _)| unit);
+    let mut h = folded_units(haystack).map(|(unit, _)| unit);
+    loop {
+        match n.next() {
+            None => return true,
+            Some(nu) => match h.next() {
+                Some(hu) if hu == nu => continue,
+                _ => return false,
+            },
+        }
+    }
+}
+
 #[cfg(feature = "alloc")]
 impl alloc::borrow::ToOwned for Insensitive {
     type Owned = crate::InsensitiveBuf;
@@ -200,4 +415,172 @@ mod tests {
         assert_ne!(Insensitive::new("ABC"), Insensitive::new("ABCD"));
         assert_ne!(Insensitive::new("ÅÄÖ"), Insensitive::new("ABCD"));
     }
+
+    #[test]
+    fn ascii_case_roundtrip_and_eq_ignore_ascii_case() {
+        let mut buf = *b"Abc-XYZ";
+        let s = Insensitive::from_bytes_mut(&mut buf);
+
+        assert!(s.eq_ignore_ascii_case(Insensitive::new("abc-xyz")));
+        assert!(!s.eq_ignore_ascii_case(Insensitive::new("abc-xyzz")));
+
+        s.make_ascii_uppercase();
+        assert_eq!(s.as_bytes(), b"ABC-XYZ");
+
+        s.make_ascii_lowercase();
+        assert_eq!(s.as_bytes(), b"abc-xyz");
+
+        // Non-ASCII bytes are left untouched by either conversion.
+        let before: [u8; 4] = *b"\xc3\xa5\xc3\x85"; // "åÅ"
+        let mut non_ascii = before;
+        let s = Insensitive::from_bytes_mut(&mut non_ascii);
+        s.make_ascii_uppercase();
+        s.make_ascii_lowercase();
+        assert_eq!(s.as_bytes(), &before);
+    }
+
+    #[test]
+    fn plain_ascii_match() {
+        let haystack = Insensitive::new("Hello World");
+
+        assert!(haystack.starts_with(Insensitive::new("hello")));
+        assert!(haystack.ends_with(Insensitive::new("WORLD")));
+        assert!(haystack.contains(Insensitive::new("lo Wo")));
+        assert_eq!(haystack.find(Insensitive::new("world")), Some(6));
+    }
+
+    #[test]
+    fn needle_that_never_matches() {
+        let haystack = Insensitive::new("Hello World");
+        let needle = Insensitive::new("xyz");
+
+        assert!(!haystack.starts_with(needle));
+        assert!(!haystack.ends_with(needle));
+        assert!(!haystack.contains(needle));
+        assert_eq!(haystack.find(needle), None);
+    }
+
+    #[test]
+    fn empty_needle_always_matches() {
+        for haystack in [Insensitive::new(""), Insensitive::new("abc")] {
+            let empty = Insensitive::new("");
+            assert!(haystack.starts_with(empty));
+            assert!(haystack.ends_with(empty));
+            assert!(haystack.contains(empty));
+            assert_eq!(haystack.find(empty), Some(0));
+        }
+    }
+
+    #[test]
+    fn fold_expands_sharp_s_in_haystack() {
+        // "ß".to_uppercase() is the two-char "SS", so a plain-ASCII needle spelling that region
+        // out must still be found inside a haystack that holds the multi-byte `ß`.
+        let haystack = Insensitive::new("straße");
+        let needle = Insensitive::new("ASSE");
+
+        assert!(haystack.ends_with(needle));
+        assert!(haystack.contains(needle));
+        // Offset points at the `a` preceding `ß` in the original bytes, not at a fold-expanded
+        // position.
+        assert_eq!(haystack.find(needle), Some(3));
+    }
+
+    #[test]
+    fn fold_expands_sharp_s_in_needle() {
+        // Same fold, but now the multi-byte `ß` needle must be found against a plain-ASCII
+        // `SS` run in the haystack.
+        let haystack = Insensitive::new("CLASSIC");
+        let needle = Insensitive::new("ß");
+
+        assert!(haystack.contains(needle));
+        assert_eq!(haystack.find(needle), Some(3));
+    }
+
+    #[test]
+    fn needle_straddles_invalid_utf8_run() {
+        let haystack_bytes: [u8; 5] = [b'a', b'b', 0xff, b'c', b'd'];
+        let haystack = Insensitive::from_bytes(&haystack_bytes);
+
+        // Case differs on both sides of the invalid byte; the byte itself must match exactly.
+        let needle_bytes: [u8; 3] = [b'B', 0xff, b'C'];
+        let needle = Insensitive::from_bytes(&needle_bytes);
+
+        assert!(haystack.contains(needle));
+        assert_eq!(haystack.find(needle), Some(1));
+
+        let mismatched_bytes: [u8; 3] = [b'B', 0xfe, b'C'];
+        let mismatched = Insensitive::from_bytes(&mismatched_bytes);
+        assert!(!haystack.contains(mismatched));
+    }
+
+    #[test]
+    fn split_and_rsplit_on_empty_and_delimiter_free_input() {
+        let empty = Insensitive::new("");
+        assert_eq!(empty.split(b'/').next(), Some(Insensitive::new("")));
+        assert_eq!(empty.rsplit(b'/').next(), Some(Insensitive::new("")));
+
+        let no_delim = Insensitive::new("abc");
+        let mut it = no_delim.split(b'/');
+        assert_eq!(it.next(), Some(Insensitive::new("abc")));
+        assert_eq!(it.next(), None);
+
+        let mut it = no_delim.rsplit(b'/');
+        assert_eq!(it.next(), Some(Insensitive::new("abc")));
+        assert_eq!(it.next(), None);
+    }
+
+    #[test]
+    fn split_and_rsplit_agree_in_reverse() {
+        let s = Insensitive::new("a/b/c");
+
+        let mut it = s.split(b'/');
+        assert_eq!(it.next(), Some(Insensitive::new("a")));
+        assert_eq!(it.next(), Some(Insensitive::new("b")));
+        assert_eq!(it.next(), Some(Insensitive::new("c")));
+        assert_eq!(it.next(), None);
+
+        let mut it = s.rsplit(b'/');
+        assert_eq!(it.next(), Some(Insensitive::new("c")));
+        assert_eq!(it.next(), Some(Insensitive::new("b")));
+        assert_eq!(it.next(), Some(Insensitive::new("a")));
+        assert_eq!(it.next(), None);
+    }
+
+    #[test]
+    fn tail_edge_cases() {
+        let s = Insensitive::new("a/b/c");
+
+        assert_eq!(s.tail(0, b'/'), Insensitive::new(""));
+        assert_eq!(s.tail(1, b'/'), Insensitive::new("c"));
+        assert_eq!(s.tail(2, b'/'), Insensitive::new("b/c"));
+        // `n` exceeding the field count returns the whole buffer.
+        assert_eq!(s.tail(10, b'/'), Insensitive::new("a/b/c"));
+
+        // A trailing delimiter leaves an empty last field.
+        let trailing = Insensitive::new("a/b/");
+        assert_eq!(trailing.tail(1, b'/'), Insensitive::new(""));
+        assert_eq!(trailing.tail(2, b'/'), Insensitive::new("b/"));
+    }
+
+    #[test]
+    fn codepoints_surfaces_invalid_bytes_individually() {
+        let bytes: [u8; 5] = [b'a', 0xff, 0xfe, b'b', 0x80];
+        let s = Insensitive::from_bytes(&bytes);
+
+        let mut it = s.codepoints();
+        assert_eq!(it.next(), Some(Ok('a')));
+        assert_eq!(it.next(), Some(Err(0xff)));
+        assert_eq!(it.next(), Some(Err(0xfe)));
+        assert_eq!(it.next(), Some(Ok('b')));
+        assert_eq!(it.next(), Some(Err(0x80)));
+        assert_eq!(it.next(), None);
+    }
+
+    #[test]
+    fn codepoints_on_valid_and_empty_input() {
+        assert_eq!(Insensitive::new("").codepoints().next(), None);
+        assert!(Insensitive::new("åäö")
+            .codepoints()
+            .eq(['å', 'ä', 'ö'].into_iter().map(Ok)));
+    }
 }
@@ -1,7 +1,16 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 #![doc = include_str!("../README.md")]
 
-pub use self::{insensitive_display::InsensitiveDisplay, insensitive_ref::Insensitive};
+pub use self::{
+    ascii_insensitive::AsciiInsensitive,
+    insensitive_display::{InsensitiveDisplay, InsensitiveLossy},
+    insensitive_escape::InsensitiveEscape,
+    insensitive_ref::Insensitive,
+};
+
+mod ascii_insensitive;
+
+mod insensitive_escape;
 
 mod insensitive_ref;
 